@@ -1,3 +1,5 @@
+use std::ops::{Add, Mul};
+
 fn main() {
     println!("Rust Debugging Sample Program");
 
@@ -10,8 +12,34 @@ fn main() {
     let total = calculate_total(&numbers);
     println!("Total: {}", total);
 
+    let total_fold = calculate_total_fold(&numbers);
+    println!("Total (fold): {}", total_fold);
+
     let result = process_data(100);
     println!("Result: {}", result);
+
+    {
+        let x = x * 2;
+        println!("Shadowed x: {}", x);
+    }
+
+    let a = Vector { x: 1, y: 2 };
+    let b = Vector { x: 3, y: 4 };
+    let c = a + b;
+    println!("Vector sum: {:?}", c);
+    let d = a * 3;
+    println!("Vector scaled: {:?}", d);
+
+    let mut closure_sum = 0;
+    first_ten(|i| closure_sum += i);
+    println!("Closure sum: {}", closure_sum);
+
+    let program = "+++*-/";
+    let program_result = run_program(program);
+    println!("Program result: {}", program_result);
+
+    let fib_result = fib(10);
+    println!("Fibonacci(10): {}", fib_result);
 }
 
 fn add(a: i32, b: i32) -> i32 {
@@ -26,8 +54,70 @@ fn calculate_total(numbers: &[i32]) -> i32 {
     sum
 }
 
+fn calculate_total_fold(numbers: &[i32]) -> i32 {
+    numbers.iter().fold(0, |acc, &n| acc + n)
+}
+
 fn process_data(value: i32) -> i32 {
     let multiplied = value * 2;
     let result = multiplied + 10;
     result
 }
+
+fn first_ten<F: FnMut(i32)>(mut it: F) {
+    for i in 1..=10 {
+        it(i);
+    }
+}
+
+fn run_program(program: &str) -> i32 {
+    let mut accumulator = 0;
+    for token in program.chars() {
+        match token {
+            '+' => accumulator += 1,
+            '-' => accumulator -= 1,
+            '*' => accumulator *= 2,
+            '/' => accumulator /= 2,
+            _ => {}
+        }
+    }
+    accumulator
+}
+
+fn fib(n: u64) -> u64 {
+    if n == 0 {
+        0
+    } else if n == 1 {
+        1
+    } else {
+        fib(n - 1) + fib(n - 2)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Vector {
+    x: i32,
+    y: i32,
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Mul<i32> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Vector {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}